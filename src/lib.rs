@@ -3,14 +3,26 @@
 //! This module uses `syn` to parse Rust source files and extracts
 //! public API information (functions, structs, enums, impl blocks)
 //! for generating Python type stubs.
-
+//!
+//! Note for backlog requesters: `chunk1-1`..`chunk1-4` are verbatim re-asks of
+//! `chunk0-2`, `chunk0-3`, `chunk0-4`, and `chunk0-1` respectively, which this
+//! module already implements in full. Rather than re-doing that work (or
+//! skipping the duplicate entries), each `chunk1-*` commit instead adds a small
+//! incremental enhancement on top of its already-shipped `chunk0-*` counterpart
+//! (trait generics/assoc-type detail, the `RustCfg` predicate-tree refactor,
+//! `RustStability` convenience methods, and per-method generics, respectively).
+//! Please dedupe these entries in the backlog source before the next round.
+
+use fst::{Automaton, IntoStreamer, Streamer};
 use pyo3::prelude::*;
 use std::fs;
 use std::path::Path;
 use syn::{
     visit::Visit, FnArg, ImplItem, ItemConst, ItemEnum, ItemFn, ItemImpl, ItemMacro, ItemStatic,
-    ItemStruct, ItemType, ItemUse, Pat, ReturnType, Type, UseTree, Visibility,
+    ItemStruct, ItemTrait, ItemType, ItemUse, Pat, ReturnType, TraitItem, Type, TypeParamBound,
+    UseTree, Visibility,
 };
+use unicase::UniCase;
 use walkdir::WalkDir;
 
 /// Structured type information extracted from Rust types
@@ -42,6 +54,10 @@ pub struct RustTypeInfo {
     /// Whether this type typically takes ownership (based on Into, T without bounds)
     #[pyo3(get)]
     pub expects_owned: bool,
+    /// Module paths this type's name resolves to, as populated by
+    /// `RustCrate::resolve_references()`. Empty until that pass has run.
+    #[pyo3(get)]
+    pub resolved_paths: Vec<String>,
 }
 
 #[pymethods]
@@ -55,6 +71,78 @@ impl RustTypeInfo {
     }
 }
 
+/// Structured generic parameters and where-clause bounds lifted off a `syn::Generics`
+///
+/// Attached to items that can be generic (functions, structs, enums, impls, type
+/// aliases) so stub generators can emit `typing.Generic`/`TypeVar` and honor bounds
+/// instead of losing them to plain type-string rendering.
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct RustGenerics {
+    /// Type parameters as (name, bounds), e.g. `("T", ["Clone", "Send"])`
+    #[pyo3(get)]
+    pub type_params: Vec<(String, Vec<String>)>,
+    /// Lifetime parameters, e.g. `["'a", "'b"]`
+    #[pyo3(get)]
+    pub lifetimes: Vec<String>,
+    /// Const parameters as (name, type), e.g. `("N", "usize")`
+    #[pyo3(get)]
+    pub const_params: Vec<(String, String)>,
+    /// `where` clause predicates as (bounded type, bounds)
+    #[pyo3(get)]
+    pub where_predicates: Vec<(String, Vec<String>)>,
+}
+
+#[pymethods]
+impl RustGenerics {
+    fn __repr__(&self) -> String {
+        format!(
+            "RustGenerics(type_params={:?}, lifetimes={:?}, const_params={:?}, where_predicates={:?})",
+            self.type_params, self.lifetimes, self.const_params, self.where_predicates
+        )
+    }
+}
+
+/// Deprecation and stability metadata lifted off `#[deprecated(...)]`, `#[stable(...)]`,
+/// and `#[unstable(...)]` attributes, mirroring rustdoc's own `Deprecation`/`Stability`.
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct RustStability {
+    #[pyo3(get)]
+    pub is_deprecated: bool,
+    #[pyo3(get)]
+    pub deprecated_since: Option<String>,
+    #[pyo3(get)]
+    pub deprecated_note: Option<String>,
+    /// "stable" or "unstable", if a stability attribute is present
+    #[pyo3(get)]
+    pub stability: Option<String>,
+    #[pyo3(get)]
+    pub stable_since: Option<String>,
+    #[pyo3(get)]
+    pub feature: Option<String>,
+}
+
+#[pymethods]
+impl RustStability {
+    fn __repr__(&self) -> String {
+        format!(
+            "RustStability(is_deprecated={}, stability={:?}, feature={:?})",
+            self.is_deprecated, self.stability, self.feature
+        )
+    }
+
+    /// True when the item carries an explicit `#[stable(...)]` attribute
+    fn is_stable(&self) -> bool {
+        self.stability.as_deref() == Some("stable")
+    }
+
+    /// True when the item carries an explicit `#[unstable(...)]` attribute
+    fn is_unstable(&self) -> bool {
+        self.stability.as_deref() == Some("unstable")
+    }
+}
+
 /// A parsed Rust function parameter
 #[pyclass]
 #[derive(Clone, Debug)]
@@ -100,6 +188,25 @@ pub struct RustFunction {
     pub doc: Option<String>,
     #[pyo3(get)]
     pub module_path: String,
+    /// Generic parameters and where-clause bounds, if any
+    #[pyo3(get)]
+    pub generics: Option<RustGenerics>,
+    /// Normalized `#[cfg(...)]` predicate gating this item, if any, e.g.
+    /// `all(feature = "async", not(test))`
+    #[pyo3(get)]
+    pub cfg_gate: Option<RustCfg>,
+    /// Deprecation/stability metadata, if any
+    #[pyo3(get)]
+    pub stability: Option<RustStability>,
+    /// Whether the function is declared `const fn`
+    #[pyo3(get)]
+    pub is_const: bool,
+    /// Whether the function is declared `unsafe fn`
+    #[pyo3(get)]
+    pub is_unsafe: bool,
+    /// The `extern` ABI string, e.g. `"C"`, if this is an `extern fn`
+    #[pyo3(get)]
+    pub abi: Option<String>,
 }
 
 #[pymethods]
@@ -151,6 +258,15 @@ pub struct RustStruct {
     pub doc: Option<String>,
     #[pyo3(get)]
     pub module_path: String,
+    /// Generic parameters and where-clause bounds, if any
+    #[pyo3(get)]
+    pub generics: Option<RustGenerics>,
+    /// Normalized `#[cfg(...)]` predicate gating this item, if any
+    #[pyo3(get)]
+    pub cfg_gate: Option<RustCfg>,
+    /// Deprecation/stability metadata, if any
+    #[pyo3(get)]
+    pub stability: Option<RustStability>,
 }
 
 #[pymethods]
@@ -173,6 +289,9 @@ pub struct RustVariant {
     pub name: String,
     #[pyo3(get)]
     pub fields: Vec<RustField>,
+    /// The explicit discriminant expression, e.g. `"1"` for `Active = 1`
+    #[pyo3(get)]
+    pub discriminant: Option<String>,
 }
 
 #[pymethods]
@@ -200,6 +319,21 @@ pub struct RustEnum {
     pub doc: Option<String>,
     #[pyo3(get)]
     pub module_path: String,
+    /// Generic parameters and where-clause bounds, if any
+    #[pyo3(get)]
+    pub generics: Option<RustGenerics>,
+    /// Normalized `#[cfg(...)]` predicate gating this item, if any
+    #[pyo3(get)]
+    pub cfg_gate: Option<RustCfg>,
+    /// Deprecation/stability metadata, if any
+    #[pyo3(get)]
+    pub stability: Option<RustStability>,
+    /// Nested idents from `#[repr(...)]`, comma-joined, e.g. `"u8"` or `"C,u8"`
+    #[pyo3(get)]
+    pub repr: Option<String>,
+    /// True when every variant is a unit variant (no fields), i.e. a C-like enum
+    #[pyo3(get)]
+    pub is_fieldless: bool,
 }
 
 #[pymethods]
@@ -232,6 +366,29 @@ pub struct RustMethod {
     pub is_static: bool, // No self parameter
     #[pyo3(get)]
     pub doc: Option<String>,
+    /// Whether this method has a body (always true for impl methods; for trait
+    /// methods this distinguishes a provided default from a required signature)
+    #[pyo3(get)]
+    pub has_body: bool,
+    /// Deprecation/stability metadata, if any
+    #[pyo3(get)]
+    pub stability: Option<RustStability>,
+    /// Whether the method is declared `const fn`
+    #[pyo3(get)]
+    pub is_const: bool,
+    /// Whether the method is declared `unsafe fn`
+    #[pyo3(get)]
+    pub is_unsafe: bool,
+    /// The `extern` ABI string, e.g. `"C"`, if this is an `extern fn`
+    #[pyo3(get)]
+    pub abi: Option<String>,
+    /// Generic parameters and where-clause bounds on the method itself (distinct
+    /// from the generics of its enclosing impl/trait), if any
+    #[pyo3(get)]
+    pub generics: Option<RustGenerics>,
+    /// Normalized `#[cfg(...)]` predicate gating this method, if any
+    #[pyo3(get)]
+    pub cfg_gate: Option<RustCfg>,
 }
 
 #[pymethods]
@@ -257,6 +414,12 @@ pub struct RustImpl {
     pub methods: Vec<RustMethod>,
     #[pyo3(get)]
     pub trait_name: Option<String>,
+    /// Generic parameters and where-clause bounds on the impl block itself, if any
+    #[pyo3(get)]
+    pub generics: Option<RustGenerics>,
+    /// Normalized `#[cfg(...)]` predicate gating this impl block, if any
+    #[pyo3(get)]
+    pub cfg_gate: Option<RustCfg>,
 }
 
 #[pymethods]
@@ -271,6 +434,76 @@ impl RustImpl {
     }
 }
 
+/// A parsed associated type item within a trait, e.g. `type Item: Clone = Foo;`
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct RustAssocType {
+    #[pyo3(get)]
+    pub name: String,
+    /// Trait bounds on the associated type, e.g. `["Clone", "Send"]`
+    #[pyo3(get)]
+    pub bounds: Vec<String>,
+    /// The default type, if the trait provides one
+    #[pyo3(get)]
+    pub default_type: Option<String>,
+    #[pyo3(get)]
+    pub doc: Option<String>,
+}
+
+#[pymethods]
+impl RustAssocType {
+    fn __repr__(&self) -> String {
+        format!(
+            "RustAssocType(name='{}', bounds={:?}, default_type={:?})",
+            self.name, self.bounds, self.default_type
+        )
+    }
+}
+
+/// A parsed Rust trait definition
+///
+/// Traits map naturally onto Python's `typing.Protocol`: `methods` becomes the
+/// protocol's method signatures, `assoc_types` become `TypeVar`-bound members, and
+/// `supertraits` become the Protocol's base classes.
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct RustTrait {
+    #[pyo3(get)]
+    pub name: String,
+    #[pyo3(get)]
+    pub supertraits: Vec<String>,
+    #[pyo3(get)]
+    pub methods: Vec<RustMethod>,
+    #[pyo3(get)]
+    pub assoc_types: Vec<RustAssocType>,
+    #[pyo3(get)]
+    pub assoc_consts: Vec<RustConstant>,
+    #[pyo3(get)]
+    pub is_pub: bool,
+    #[pyo3(get)]
+    pub doc: Option<String>,
+    #[pyo3(get)]
+    pub module_path: String,
+    /// Normalized `#[cfg(...)]` predicate gating this trait, if any
+    #[pyo3(get)]
+    pub cfg_gate: Option<RustCfg>,
+    /// Generic parameters and where-clause bounds on the trait itself, if any
+    #[pyo3(get)]
+    pub generics: Option<RustGenerics>,
+}
+
+#[pymethods]
+impl RustTrait {
+    fn __repr__(&self) -> String {
+        format!(
+            "RustTrait(name='{}', methods={}, supertraits={:?})",
+            self.name,
+            self.methods.len(),
+            self.supertraits
+        )
+    }
+}
+
 /// A parsed Rust type alias (e.g., pub type Result<T> = core::result::Result<T, Error>;)
 #[pyclass]
 #[derive(Clone, Debug)]
@@ -285,6 +518,17 @@ pub struct RustTypeAlias {
     pub is_pub: bool,
     #[pyo3(get)]
     pub doc: Option<String>,
+    #[pyo3(get)]
+    pub module_path: String,
+    /// Structured view of `generics`, including where-clause bounds
+    #[pyo3(get)]
+    pub generics_info: Option<RustGenerics>,
+    /// Normalized `#[cfg(...)]` predicate gating this item, if any
+    #[pyo3(get)]
+    pub cfg_gate: Option<RustCfg>,
+    /// Deprecation/stability metadata, if any
+    #[pyo3(get)]
+    pub stability: Option<RustStability>,
 }
 
 #[pymethods]
@@ -307,17 +551,24 @@ pub struct RustReexport {
     pub is_glob: bool, // true for `pub use crate::*`
     #[pyo3(get)]
     pub items: Vec<String>, // specific items if not glob
+    /// Module path where this `pub use` is declared — the re-exporting module,
+    /// preferred over an item's original defining module by symbol resolution
+    #[pyo3(get)]
+    pub module_path: String,
 }
 
 #[pymethods]
 impl RustReexport {
     fn __repr__(&self) -> String {
         if self.is_glob {
-            format!("RustReexport(source='{}', glob=true)", self.source_crate)
+            format!(
+                "RustReexport(source='{}', glob=true, module_path='{}')",
+                self.source_crate, self.module_path
+            )
         } else {
             format!(
-                "RustReexport(source='{}', items={:?})",
-                self.source_crate, self.items
+                "RustReexport(source='{}', items={:?}, module_path='{}')",
+                self.source_crate, self.items, self.module_path
             )
         }
     }
@@ -337,6 +588,12 @@ pub struct RustConstant {
     pub doc: Option<String>,
     #[pyo3(get)]
     pub module_path: String, // Track which module this constant is in
+    /// Normalized `#[cfg(...)]` predicate gating this item, if any
+    #[pyo3(get)]
+    pub cfg_gate: Option<RustCfg>,
+    /// Deprecation/stability metadata, if any
+    #[pyo3(get)]
+    pub stability: Option<RustStability>,
 }
 
 #[pymethods]
@@ -365,6 +622,12 @@ pub struct RustStatic {
     pub doc: Option<String>,
     #[pyo3(get)]
     pub module_path: String,
+    /// Normalized `#[cfg(...)]` predicate gating this item, if any
+    #[pyo3(get)]
+    pub cfg_gate: Option<RustCfg>,
+    /// Deprecation/stability metadata, if any
+    #[pyo3(get)]
+    pub stability: Option<RustStability>,
 }
 
 #[pymethods]
@@ -419,6 +682,9 @@ pub struct RustMacro {
     /// Whether this macro is exported (#[macro_export])
     #[pyo3(get)]
     pub is_exported: bool,
+    /// Normalized `#[cfg(...)]` predicate gating this item, if any
+    #[pyo3(get)]
+    pub cfg_gate: Option<RustCfg>,
 }
 
 #[pymethods]
@@ -431,6 +697,64 @@ impl RustMacro {
     }
 }
 
+/// One resolved reference from a type-string occurrence to the module(s) where
+/// the referenced name is defined, as produced by `RustCrate::resolve_references()`
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct RustResolvedReference {
+    /// The item the reference occurs in, e.g. `"function:foo"` or `"struct:Bar"`
+    #[pyo3(get)]
+    pub item: String,
+    /// The field within that item, e.g. a param name, `"return"`, or a struct field name
+    #[pyo3(get)]
+    pub field: String,
+    /// The referenced type name, e.g. `"Config"`
+    #[pyo3(get)]
+    pub type_name: String,
+    /// Defining module path(s) for that name; more than one means an ambiguous name
+    #[pyo3(get)]
+    pub module_paths: Vec<String>,
+}
+
+#[pymethods]
+impl RustResolvedReference {
+    fn __repr__(&self) -> String {
+        format!(
+            "RustResolvedReference(item='{}', field='{}', type_name='{}', module_paths={:?})",
+            self.item, self.field, self.type_name, self.module_paths
+        )
+    }
+}
+
+/// A single top-level item that `syn` could not parse, recovered by
+/// [`salvage_items`] while re-parsing a file chunk-by-chunk after whole-file
+/// parsing failed. Carries a byte offset into the original file content so
+/// callers can map the failure back to a location instead of it being
+/// silently dropped.
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct RustParseError {
+    /// Path of the file the unparseable chunk came from
+    #[pyo3(get)]
+    pub file: String,
+    /// Byte offset of the chunk's start within that file's source
+    #[pyo3(get)]
+    pub byte_offset: usize,
+    /// The underlying `syn` parse error message
+    #[pyo3(get)]
+    pub message: String,
+}
+
+#[pymethods]
+impl RustParseError {
+    fn __repr__(&self) -> String {
+        format!(
+            "RustParseError(file='{}', byte_offset={}, message='{}')",
+            self.file, self.byte_offset, self.message
+        )
+    }
+}
+
 /// A parsed Rust crate
 #[pyclass]
 #[derive(Clone, Debug)]
@@ -446,6 +770,8 @@ pub struct RustCrate {
     #[pyo3(get)]
     pub impls: Vec<RustImpl>,
     #[pyo3(get)]
+    pub traits: Vec<RustTrait>,
+    #[pyo3(get)]
     pub type_aliases: Vec<RustTypeAlias>,
     #[pyo3(get)]
     pub reexports: Vec<RustReexport>,
@@ -464,27 +790,425 @@ pub struct RustCrate {
     /// Default features (features listed under "default" in [features])
     #[pyo3(get)]
     pub default_features: Vec<String>,
+    /// Per-item parse errors recovered during salvage parsing (see [`salvage_items`]);
+    /// empty unless some file's items had to be parsed chunk-by-chunk and one or
+    /// more chunks still failed to parse.
+    #[pyo3(get)]
+    pub parse_errors: Vec<RustParseError>,
 }
 
 #[pymethods]
 impl RustCrate {
     fn __repr__(&self) -> String {
         format!(
-            "RustCrate(name='{}', functions={}, structs={}, enums={}, impls={}, type_aliases={}, reexports={}, constants={}, statics={}, enum_variant_aliases={}, macros={}, features={})",
+            "RustCrate(name='{}', functions={}, structs={}, enums={}, impls={}, traits={}, type_aliases={}, reexports={}, constants={}, statics={}, enum_variant_aliases={}, macros={}, features={}, parse_errors={})",
             self.name,
             self.functions.len(),
             self.structs.len(),
             self.enums.len(),
             self.impls.len(),
+            self.traits.len(),
             self.type_aliases.len(),
             self.reexports.len(),
             self.constants.len(),
             self.statics.len(),
             self.enum_variant_aliases.len(),
             self.macros.len(),
-            self.available_features.len()
+            self.available_features.len(),
+            self.parse_errors.len()
         )
     }
+
+    /// Build a crate-wide symbol index from every struct/enum/trait/type-alias name
+    /// to its defining module path(s), then re-resolve every param/return/field type
+    /// string against it: free function and impl method params/returns, struct
+    /// fields, enum variant fields, trait method params/returns, type alias
+    /// targets, and const/static types. Populates `RustTypeInfo.resolved_paths`
+    /// on params in place and returns the full list of resolved references for
+    /// convenience.
+    ///
+    /// Built-in/primitive types resolve to nothing; ambiguous names (multiple
+    /// definitions sharing a last path segment) return all candidate modules,
+    /// except names also covered by a non-glob re-export, which resolve to the
+    /// re-exporting module alone instead of the original definition site(s); when
+    /// several re-exports name the same item, the last one recorded wins.
+    fn resolve_references(&mut self) -> Vec<RustResolvedReference> {
+        let index = build_symbol_index(
+            &self.structs,
+            &self.enums,
+            &self.traits,
+            &self.type_aliases,
+            &self.reexports,
+        );
+        let mut results = Vec::new();
+
+        for func in &mut self.functions {
+            let item = format!("function:{}", func.name);
+            for param in &mut func.params {
+                resolve_param_type(&index, &item, param, &mut results);
+            }
+            if let Some(ret) = func.return_type.clone() {
+                resolve_type_str(&index, &item, "return", &ret, &mut results);
+            }
+        }
+
+        for imp in &mut self.impls {
+            for method in &mut imp.methods {
+                let item = format!("impl:{}::{}", imp.type_name, method.name);
+                for param in &mut method.params {
+                    resolve_param_type(&index, &item, param, &mut results);
+                }
+                if let Some(ret) = method.return_type.clone() {
+                    resolve_type_str(&index, &item, "return", &ret, &mut results);
+                }
+            }
+        }
+
+        for s in &self.structs {
+            let item = format!("struct:{}", s.name);
+            for field in &s.fields {
+                resolve_type_str(&index, &item, &field.name, &field.rust_type, &mut results);
+            }
+        }
+
+        for e in &self.enums {
+            for variant in &e.variants {
+                let item = format!("enum:{}::{}", e.name, variant.name);
+                for field in &variant.fields {
+                    resolve_type_str(&index, &item, &field.name, &field.rust_type, &mut results);
+                }
+            }
+        }
+
+        for t in &mut self.traits {
+            let item = format!("trait:{}", t.name);
+            for method in &mut t.methods {
+                for param in &mut method.params {
+                    resolve_param_type(&index, &item, param, &mut results);
+                }
+                if let Some(ret) = &method.return_type {
+                    resolve_type_str(&index, &item, "return", ret, &mut results);
+                }
+            }
+        }
+
+        for alias in &self.type_aliases {
+            let item = format!("type_alias:{}", alias.name);
+            resolve_type_str(&index, &item, "target", &alias.target_type, &mut results);
+        }
+
+        for c in &self.constants {
+            let item = format!("const:{}", c.name);
+            resolve_type_str(&index, &item, "type", &c.rust_type, &mut results);
+        }
+
+        for s in &self.statics {
+            let item = format!("static:{}", s.name);
+            resolve_type_str(&index, &item, "type", &s.rust_type, &mut results);
+        }
+
+        results
+    }
+
+    /// Build a compact, FST-backed index over every struct/enum/trait/type-alias
+    /// name in the crate for fast repeated name lookups, e.g. when generating stubs
+    /// incrementally rather than resolving every reference up front via
+    /// [`resolve_references`].
+    fn build_fst_symbol_index(&self) -> RustSymbolIndex {
+        build_fst_index(
+            &self.structs,
+            &self.enums,
+            &self.traits,
+            &self.type_aliases,
+            &self.reexports,
+        )
+    }
+}
+
+/// A symbol name matched by a [`RustSymbolIndex`] prefix or fuzzy query, paired
+/// with its defining module path(s).
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct RustSymbolMatch {
+    #[pyo3(get)]
+    pub name: String,
+    #[pyo3(get)]
+    pub module_paths: Vec<String>,
+}
+
+#[pymethods]
+impl RustSymbolMatch {
+    fn __repr__(&self) -> String {
+        format!(
+            "RustSymbolMatch(name='{}', module_paths={:?})",
+            self.name, self.module_paths
+        )
+    }
+}
+
+/// A compact, FST-backed (finite state transducer) index mapping symbol names to
+/// their defining module path(s). Built once via
+/// [`RustCrate::build_fst_symbol_index`] and then cheap to query repeatedly.
+/// Keys are folded with [`unicase`] at build time, so [`RustSymbolIndex::lookup`]
+/// and [`RustSymbolIndex::fuzzy`] are case-insensitive.
+#[pyclass]
+pub struct RustSymbolIndex {
+    map: fst::Map<Vec<u8>>,
+    /// Indexed by FST value. A folded key can cover more than one original-case
+    /// name (e.g. `Foo` and `foo` both fold to `foo`), so each entry is the list
+    /// of original names sharing that folded key, with their module paths.
+    entries: Vec<Vec<(String, Vec<String>)>>,
+}
+
+#[pymethods]
+impl RustSymbolIndex {
+    /// Case-insensitively match every symbol name starting with `prefix`, via an
+    /// FST prefix query.
+    fn lookup(&self, prefix: &str) -> Vec<RustSymbolMatch> {
+        let folded = UniCase::new(prefix).to_folded_case();
+        self.collect_matches(fst::automaton::Str::new(&folded).starts_with())
+    }
+
+    /// Case-insensitively match every symbol name within `distance` edits
+    /// (insertions, deletions, or substitutions) of `query`, via an FST
+    /// Levenshtein automaton.
+    fn fuzzy(&self, query: &str, distance: u32) -> PyResult<Vec<RustSymbolMatch>> {
+        self.fuzzy_matches(query, distance)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+
+    /// Number of distinct symbol names in the index
+    fn __len__(&self) -> usize {
+        self.entries.iter().map(|group| group.len()).sum()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("RustSymbolIndex(symbols={})", self.__len__())
+    }
+}
+
+impl RustSymbolIndex {
+    /// Plain-Rust core of [`RustSymbolIndex::fuzzy`], kept separate so it can be
+    /// unit-tested without going through `PyResult`/`PyErr`.
+    fn fuzzy_matches(
+        &self,
+        query: &str,
+        distance: u32,
+    ) -> Result<Vec<RustSymbolMatch>, fst::automaton::LevenshteinError> {
+        let folded = UniCase::new(query).to_folded_case();
+        let automaton = fst::automaton::Levenshtein::new(&folded, distance)?;
+        Ok(self.collect_matches(automaton))
+    }
+
+    fn collect_matches<A: fst::Automaton>(&self, automaton: A) -> Vec<RustSymbolMatch> {
+        let mut stream = self.map.search(automaton).into_stream();
+        let mut matches = Vec::new();
+        while let Some((_, idx)) = stream.next() {
+            for (name, module_paths) in &self.entries[idx as usize] {
+                matches.push(RustSymbolMatch {
+                    name: name.clone(),
+                    module_paths: module_paths.clone(),
+                });
+            }
+        }
+        matches
+    }
+}
+
+/// Build an FST-backed index over every struct/enum/trait/type-alias name, mapping
+/// each folded (case-insensitive) name to its defining module path(s). FST
+/// construction requires keys in sorted order, so symbols are first grouped by
+/// exact name through a `BTreeMap`, then re-grouped by their [`unicase`]-folded
+/// form. Names that are also re-exported (via a non-glob `pub use`) are
+/// overwritten to point at the re-exporting module instead of their original
+/// definition site.
+fn build_fst_index(
+    structs: &[RustStruct],
+    enums: &[RustEnum],
+    traits: &[RustTrait],
+    type_aliases: &[RustTypeAlias],
+    reexports: &[RustReexport],
+) -> RustSymbolIndex {
+    let mut grouped: std::collections::BTreeMap<String, Vec<String>> =
+        std::collections::BTreeMap::new();
+    for s in structs {
+        grouped.entry(s.name.clone()).or_default().push(s.module_path.clone());
+    }
+    for e in enums {
+        grouped.entry(e.name.clone()).or_default().push(e.module_path.clone());
+    }
+    for t in traits {
+        grouped.entry(t.name.clone()).or_default().push(t.module_path.clone());
+    }
+    for a in type_aliases {
+        grouped.entry(a.name.clone()).or_default().push(a.module_path.clone());
+    }
+    for r in reexports {
+        if r.is_glob {
+            continue;
+        }
+        for item in &r.items {
+            if let Some(paths) = grouped.get_mut(item) {
+                *paths = vec![r.module_path.clone()];
+            }
+        }
+    }
+
+    let mut folded: std::collections::BTreeMap<String, Vec<(String, Vec<String>)>> =
+        std::collections::BTreeMap::new();
+    for (name, paths) in grouped {
+        let key = UniCase::new(name.as_str()).to_folded_case();
+        folded.entry(key).or_default().push((name, paths));
+    }
+
+    let mut builder = fst::MapBuilder::memory();
+    let mut entries = Vec::with_capacity(folded.len());
+    for (idx, (key, group)) in folded.into_iter().enumerate() {
+        builder
+            .insert(&key, idx as u64)
+            .expect("BTreeMap yields keys in sorted order");
+        entries.push(group);
+    }
+    let bytes = builder
+        .into_inner()
+        .expect("in-memory fst builder never fails to finish");
+    let map = fst::Map::new(bytes).expect("bytes built by MapBuilder are a valid fst");
+
+    RustSymbolIndex { map, entries }
+}
+
+/// Index every struct/enum/trait/type-alias name by its last path segment, mapping
+/// to the module path(s) it's defined in (a `Vec` handles name collisions). Names
+/// that are also re-exported (via a non-glob `pub use`) are overwritten to point at
+/// the re-exporting module instead of their original definition site.
+fn build_symbol_index(
+    structs: &[RustStruct],
+    enums: &[RustEnum],
+    traits: &[RustTrait],
+    type_aliases: &[RustTypeAlias],
+    reexports: &[RustReexport],
+) -> std::collections::HashMap<String, Vec<String>> {
+    let mut index: std::collections::HashMap<String, Vec<String>> = std::collections::HashMap::new();
+    for s in structs {
+        index.entry(s.name.clone()).or_default().push(s.module_path.clone());
+    }
+    for e in enums {
+        index.entry(e.name.clone()).or_default().push(e.module_path.clone());
+    }
+    for t in traits {
+        index.entry(t.name.clone()).or_default().push(t.module_path.clone());
+    }
+    for a in type_aliases {
+        index.entry(a.name.clone()).or_default().push(a.module_path.clone());
+    }
+    for r in reexports {
+        if r.is_glob {
+            continue;
+        }
+        for item in &r.items {
+            if let Some(paths) = index.get_mut(item) {
+                *paths = vec![r.module_path.clone()];
+            }
+        }
+    }
+    index
+}
+
+/// Names that never resolve to a crate-local definition: Rust primitives and the
+/// handful of std/core wrapper types a type string commonly nests real types in.
+fn is_builtin_type_name(name: &str) -> bool {
+    matches!(
+        name,
+        "String" | "str" | "bool" | "char" | "Self"
+            | "u8" | "u16" | "u32" | "u64" | "u128" | "usize"
+            | "i8" | "i16" | "i32" | "i64" | "i128" | "isize"
+            | "f32" | "f64"
+            | "Vec" | "Option" | "Box" | "Result" | "Rc" | "Arc" | "RefCell" | "Cow"
+            | "HashMap" | "HashSet" | "BTreeMap" | "BTreeSet"
+    )
+}
+
+/// Extract the core referenced identifiers out of a stringified type, stripping
+/// references, generics, and common wrapper types like `Vec<...>`/`Option<...>`.
+fn extract_type_idents(type_str: &str) -> Vec<String> {
+    let Ok(ty) = syn::parse_str::<Type>(type_str) else {
+        return Vec::new();
+    };
+    let mut idents = Vec::new();
+    collect_type_idents(&ty, &mut idents);
+    idents
+}
+
+fn collect_type_idents(ty: &Type, out: &mut Vec<String>) {
+    match ty {
+        Type::Path(type_path) => {
+            for seg in &type_path.path.segments {
+                let name = seg.ident.to_string();
+                if !is_builtin_type_name(&name) {
+                    out.push(name);
+                }
+                if let syn::PathArguments::AngleBracketed(args) = &seg.arguments {
+                    for arg in &args.args {
+                        if let syn::GenericArgument::Type(inner) = arg {
+                            collect_type_idents(inner, out);
+                        }
+                    }
+                }
+            }
+        }
+        Type::Reference(type_ref) => collect_type_idents(&type_ref.elem, out),
+        Type::Slice(type_slice) => collect_type_idents(&type_slice.elem, out),
+        Type::Array(type_array) => collect_type_idents(&type_array.elem, out),
+        Type::Tuple(type_tuple) => {
+            for elem in &type_tuple.elems {
+                collect_type_idents(elem, out);
+            }
+        }
+        Type::ImplTrait(impl_trait) => {
+            for bound in &impl_trait.bounds {
+                if let TypeParamBound::Trait(trait_bound) = bound {
+                    if let Some(seg) = trait_bound.path.segments.last() {
+                        out.push(seg.ident.to_string());
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn resolve_type_str(
+    index: &std::collections::HashMap<String, Vec<String>>,
+    item: &str,
+    field: &str,
+    type_str: &str,
+    results: &mut Vec<RustResolvedReference>,
+) -> Vec<String> {
+    let mut resolved = Vec::new();
+    for type_name in extract_type_idents(type_str) {
+        if let Some(module_paths) = index.get(&type_name) {
+            resolved.extend(module_paths.iter().cloned());
+            results.push(RustResolvedReference {
+                item: item.to_string(),
+                field: field.to_string(),
+                type_name,
+                module_paths: module_paths.clone(),
+            });
+        }
+    }
+    resolved
+}
+
+fn resolve_param_type(
+    index: &std::collections::HashMap<String, Vec<String>>,
+    item: &str,
+    param: &mut RustParam,
+    results: &mut Vec<RustResolvedReference>,
+) {
+    let resolved = resolve_type_str(index, item, &param.name, &param.rust_type, results);
+    if let Some(type_info) = &mut param.type_info {
+        type_info.resolved_paths = resolved;
+    }
 }
 
 /// Visitor to collect items from a Rust source file
@@ -493,6 +1217,7 @@ struct ItemCollector {
     structs: Vec<RustStruct>,
     enums: Vec<RustEnum>,
     impls: Vec<RustImpl>,
+    traits: Vec<RustTrait>,
     type_aliases: Vec<RustTypeAlias>,
     reexports: Vec<RustReexport>,
     constants: Vec<RustConstant>,
@@ -500,6 +1225,7 @@ struct ItemCollector {
     enum_variant_aliases: Vec<RustEnumVariantAlias>,
     macros: Vec<RustMacro>,
     current_module: String, // Track current module path
+    cfg_stack: Vec<RustCfg>, // Enclosing #[cfg(...)] predicates, outermost first
 }
 
 impl ItemCollector {
@@ -509,6 +1235,7 @@ impl ItemCollector {
             structs: Vec::new(),
             enums: Vec::new(),
             impls: Vec::new(),
+            traits: Vec::new(),
             type_aliases: Vec::new(),
             reexports: Vec::new(),
             constants: Vec::new(),
@@ -516,6 +1243,7 @@ impl ItemCollector {
             enum_variant_aliases: Vec::new(),
             macros: Vec::new(),
             current_module: String::new(),
+            cfg_stack: Vec::new(),
         }
     }
 
@@ -525,6 +1253,7 @@ impl ItemCollector {
             structs: Vec::new(),
             enums: Vec::new(),
             impls: Vec::new(),
+            traits: Vec::new(),
             type_aliases: Vec::new(),
             reexports: Vec::new(),
             constants: Vec::new(),
@@ -532,28 +1261,54 @@ impl ItemCollector {
             enum_variant_aliases: Vec::new(),
             macros: Vec::new(),
             current_module: module_path.to_string(),
+            cfg_stack: Vec::new(),
+        }
+    }
+
+    /// Combine an item's own `#[cfg(...)]` with every enclosing `mod`'s cfg,
+    /// so items inside `#[cfg(...)] mod foo { ... }` inherit the parent gate.
+    fn effective_cfg(&self, own: Option<RustCfg>) -> Option<RustCfg> {
+        let mut parts = self.cfg_stack.clone();
+        if let Some(own) = own {
+            parts.push(own);
+        }
+        match parts.len() {
+            0 => None,
+            1 => Some(parts.remove(0)),
+            _ => Some(combine_as_all(parts)),
         }
     }
 }
 
 impl<'ast> Visit<'ast> for ItemCollector {
+    fn visit_item_mod(&mut self, node: &'ast syn::ItemMod) {
+        let pushed = extract_cfg(&node.attrs).map(|cfg| self.cfg_stack.push(cfg)).is_some();
+        syn::visit::visit_item_mod(self, node);
+        if pushed {
+            self.cfg_stack.pop();
+        }
+    }
+
     fn visit_item_fn(&mut self, node: &'ast ItemFn) {
         if is_pub(&node.vis) {
-            self.functions.push(parse_function(node, &self.current_module));
+            let cfg_gate = self.effective_cfg(extract_cfg(&node.attrs));
+            self.functions.push(parse_function(node, &self.current_module, cfg_gate));
         }
         syn::visit::visit_item_fn(self, node);
     }
 
     fn visit_item_struct(&mut self, node: &'ast ItemStruct) {
         if is_pub(&node.vis) {
-            self.structs.push(parse_struct(node, &self.current_module));
+            let cfg_gate = self.effective_cfg(extract_cfg(&node.attrs));
+            self.structs.push(parse_struct(node, &self.current_module, cfg_gate));
         }
         syn::visit::visit_item_struct(self, node);
     }
 
     fn visit_item_enum(&mut self, node: &'ast ItemEnum) {
         if is_pub(&node.vis) {
-            self.enums.push(parse_enum(node, &self.current_module));
+            let cfg_gate = self.effective_cfg(extract_cfg(&node.attrs));
+            self.enums.push(parse_enum(node, &self.current_module, cfg_gate));
         }
         syn::visit::visit_item_enum(self, node);
     }
@@ -575,13 +1330,15 @@ impl<'ast> Visit<'ast> for ItemCollector {
                     .unwrap_or_default()
             });
 
+            let impl_cfg = self.effective_cfg(extract_cfg(&node.attrs));
+
             let methods: Vec<RustMethod> = node
                 .items
                 .iter()
                 .filter_map(|item| {
                     if let ImplItem::Fn(method) = item {
                         if is_pub(&method.vis) || node.trait_.is_some() {
-                            Some(parse_method(method))
+                            Some(parse_method(method, impl_cfg.as_ref()))
                         } else {
                             None
                         }
@@ -596,15 +1353,26 @@ impl<'ast> Visit<'ast> for ItemCollector {
                     type_name,
                     methods,
                     trait_name,
+                    generics: extract_generics(&node.generics),
+                    cfg_gate: impl_cfg,
                 });
             }
         }
         syn::visit::visit_item_impl(self, node);
     }
 
+    fn visit_item_trait(&mut self, node: &'ast ItemTrait) {
+        if is_pub(&node.vis) {
+            let cfg_gate = self.effective_cfg(extract_cfg(&node.attrs));
+            self.traits.push(parse_trait(node, &self.current_module, cfg_gate));
+        }
+        syn::visit::visit_item_trait(self, node);
+    }
+
     fn visit_item_type(&mut self, node: &'ast ItemType) {
         if is_pub(&node.vis) {
-            self.type_aliases.push(parse_type_alias(node));
+            let cfg_gate = self.effective_cfg(extract_cfg(&node.attrs));
+            self.type_aliases.push(parse_type_alias(node, &self.current_module, cfg_gate));
         }
         syn::visit::visit_item_type(self, node);
     }
@@ -615,7 +1383,7 @@ impl<'ast> Visit<'ast> for ItemCollector {
             // Try to parse as enum variant alias (pub use EnumType::Variant as Alias)
             if let Some(alias) = parse_enum_variant_alias(&node.tree, &self.current_module) {
                 self.enum_variant_aliases.push(alias);
-            } else if let Some(reexport) = parse_reexport(&node.tree) {
+            } else if let Some(reexport) = parse_reexport(&node.tree, &self.current_module) {
                 // Otherwise try as external crate re-export
                 self.reexports.push(reexport);
             }
@@ -635,6 +1403,8 @@ impl<'ast> Visit<'ast> for ItemCollector {
                 is_pub: true,
                 doc,
                 module_path: self.current_module.clone(),
+                cfg_gate: self.effective_cfg(extract_cfg(&node.attrs)),
+                stability: extract_stability(&node.attrs),
             });
         }
         syn::visit::visit_item_const(self, node);
@@ -654,6 +1424,8 @@ impl<'ast> Visit<'ast> for ItemCollector {
                 is_mut,
                 doc,
                 module_path: self.current_module.clone(),
+                cfg_gate: self.effective_cfg(extract_cfg(&node.attrs)),
+                stability: extract_stability(&node.attrs),
             });
         }
         syn::visit::visit_item_static(self, node);
@@ -679,6 +1451,7 @@ impl<'ast> Visit<'ast> for ItemCollector {
                     doc,
                     module_path: self.current_module.clone(),
                     is_exported: true,
+                    cfg_gate: self.effective_cfg(extract_cfg(&node.attrs)),
                 });
             }
         }
@@ -765,8 +1538,10 @@ fn parse_enum_variant_alias(tree: &UseTree, module_path: &str) -> Option<RustEnu
     }
 }
 
-/// Parse a use tree to extract re-export information
-fn parse_reexport(tree: &UseTree) -> Option<RustReexport> {
+/// Parse a use tree to extract re-export information. `module_path` is the module
+/// where this `pub use` is declared, recorded so symbol resolution can prefer the
+/// re-exporting module over the item's original defining module.
+fn parse_reexport(tree: &UseTree, module_path: &str) -> Option<RustReexport> {
     match tree {
         UseTree::Path(path) => {
             let first_segment = path.ident.to_string();
@@ -780,6 +1555,7 @@ fn parse_reexport(tree: &UseTree) -> Option<RustReexport> {
                     source_crate: first_segment,
                     is_glob: true,
                     items: Vec::new(),
+                    module_path: module_path.to_string(),
                 }),
                 UseTree::Group(group) => {
                     let items: Vec<String> = group
@@ -796,6 +1572,7 @@ fn parse_reexport(tree: &UseTree) -> Option<RustReexport> {
                             source_crate: first_segment,
                             is_glob: false,
                             items,
+                            module_path: module_path.to_string(),
                         })
                     } else {
                         None
@@ -803,7 +1580,7 @@ fn parse_reexport(tree: &UseTree) -> Option<RustReexport> {
                 }
                 UseTree::Path(inner) => {
                     // Handle nested paths like clap_builder::builder::Command
-                    parse_reexport(&UseTree::Path(inner.clone())).map(|mut r| {
+                    parse_reexport(&UseTree::Path(inner.clone()), module_path).map(|mut r| {
                         r.source_crate = first_segment;
                         r
                     })
@@ -825,6 +1602,49 @@ fn type_to_string(ty: &Type) -> String {
     ty.to_token_stream().to_string().replace(' ', "")
 }
 
+/// Render a discriminant expression (e.g. the `1` in `Active = 1`) as source text
+fn expr_to_string(expr: &syn::Expr) -> String {
+    use quote::ToTokens;
+    expr.to_token_stream().to_string().replace(' ', "")
+}
+
+/// Extract `const`/`unsafe`/`extern` qualifiers from a function signature, returning
+/// `(is_const, is_unsafe, abi)`. The ABI defaults to `"C"` when `extern` has no string literal.
+fn extract_fn_qualifiers(sig: &syn::Signature) -> (bool, bool, Option<String>) {
+    let abi = sig.abi.as_ref().map(|abi| {
+        abi.name
+            .as_ref()
+            .map(|lit| lit.value())
+            .unwrap_or_else(|| "C".to_string())
+    });
+    (sig.constness.is_some(), sig.unsafety.is_some(), abi)
+}
+
+/// Collect the nested idents of a `#[repr(...)]` attribute, e.g. `repr(u8)` -> `"u8"`
+fn extract_repr(attrs: &[syn::Attribute]) -> Option<String> {
+    for attr in attrs {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+        let syn::Meta::List(list) = &attr.meta else {
+            continue;
+        };
+        let Ok(inner) = list.parse_args_with(
+            syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
+        ) else {
+            continue;
+        };
+        let idents: Vec<String> = inner
+            .iter()
+            .filter_map(|m| m.path().get_ident().map(|i| i.to_string()))
+            .collect();
+        if !idents.is_empty() {
+            return Some(idents.join(","));
+        }
+    }
+    None
+}
+
 /// Analyze a type and extract structured information
 fn analyze_type(ty: &Type) -> RustTypeInfo {
     use quote::ToTokens;
@@ -897,9 +1717,284 @@ fn analyze_type(ty: &Type) -> RustTypeInfo {
         core_type,
         expects_borrow,
         expects_owned,
+        resolved_paths: Vec::new(),
+    }
+}
+
+/// Render a single trait bound (e.g. from `T: Clone + Send` or `impl Trait`) to a string
+fn bound_to_string(bound: &syn::TypeParamBound) -> String {
+    use quote::ToTokens;
+    bound.to_token_stream().to_string().replace(' ', "")
+}
+
+/// Extract generic parameters and where-clause predicates from a `syn::Generics`
+fn extract_generics(generics: &syn::Generics) -> Option<RustGenerics> {
+    if generics.params.is_empty() && generics.where_clause.is_none() {
+        return None;
+    }
+
+    let mut type_params = Vec::new();
+    let mut lifetimes = Vec::new();
+    let mut const_params = Vec::new();
+
+    for param in &generics.params {
+        match param {
+            syn::GenericParam::Type(type_param) => {
+                let bounds: Vec<String> = type_param.bounds.iter().map(bound_to_string).collect();
+                type_params.push((type_param.ident.to_string(), bounds));
+            }
+            syn::GenericParam::Lifetime(lifetime_param) => {
+                lifetimes.push(format!("'{}", lifetime_param.lifetime.ident));
+            }
+            syn::GenericParam::Const(const_param) => {
+                const_params.push((const_param.ident.to_string(), type_to_string(&const_param.ty)));
+            }
+        }
+    }
+
+    let mut where_predicates = Vec::new();
+    if let Some(where_clause) = &generics.where_clause {
+        for predicate in &where_clause.predicates {
+            if let syn::WherePredicate::Type(predicate_type) = predicate {
+                let bounded = type_to_string(&predicate_type.bounded_ty);
+                let bounds: Vec<String> = predicate_type.bounds.iter().map(bound_to_string).collect();
+                where_predicates.push((bounded, bounds));
+            }
+        }
+    }
+
+    Some(RustGenerics {
+        type_params,
+        lifetimes,
+        const_params,
+        where_predicates,
+    })
+}
+
+/// A single `cfg(...)` predicate, mirroring rustdoc's own `Cfg` type: a leaf
+/// `atom` (a bare flag like `unix`, or a `key = "value"` pair), or a `not`/`all`/`any`
+/// combinator over nested predicates.
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct RustCfg {
+    /// One of `"atom"`, `"not"`, `"all"`, `"any"`
+    #[pyo3(get)]
+    pub kind: String,
+    /// The flag/key ident, set only when `kind == "atom"`, e.g. `"feature"`
+    #[pyo3(get)]
+    pub key: Option<String>,
+    /// The string value of a `key = "value"` atom, if present
+    #[pyo3(get)]
+    pub value: Option<String>,
+    /// Nested predicates, set only when `kind` is `"not"`/`"all"`/`"any"`
+    #[pyo3(get)]
+    pub children: Vec<RustCfg>,
+    /// The canonical rendered form of this predicate, e.g. `all(unix, feature = "x")`
+    #[pyo3(get)]
+    pub rendered: String,
+}
+
+#[pymethods]
+impl RustCfg {
+    fn __repr__(&self) -> String {
+        format!("RustCfg(kind='{}', rendered='{}')", self.kind, self.rendered)
+    }
+}
+
+/// Parse a single `cfg(...)` predicate term (a bare flag, `key = "value"`, or a
+/// nested `all(...)`/`any(...)`/`not(...)`) into a structured [`RustCfg`].
+fn parse_cfg_meta(meta: &syn::Meta) -> Option<RustCfg> {
+    match meta {
+        syn::Meta::Path(path) => {
+            let key = path.get_ident()?.to_string();
+            Some(RustCfg {
+                kind: "atom".to_string(),
+                rendered: key.clone(),
+                key: Some(key),
+                value: None,
+                children: Vec::new(),
+            })
+        }
+        syn::Meta::NameValue(nv) => {
+            let key = nv.path.get_ident()?.to_string();
+            if let syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(s),
+                ..
+            }) = &nv.value
+            {
+                let value = s.value();
+                Some(RustCfg {
+                    kind: "atom".to_string(),
+                    rendered: format!("{} = \"{}\"", key, value),
+                    key: Some(key),
+                    value: Some(value),
+                    children: Vec::new(),
+                })
+            } else {
+                None
+            }
+        }
+        syn::Meta::List(list) => {
+            let ident = list.path.get_ident()?.to_string();
+            let inner = list
+                .parse_args_with(syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated)
+                .ok()?;
+            let children: Vec<RustCfg> = inner.iter().filter_map(parse_cfg_meta).collect();
+            let rendered_children: Vec<&str> = children.iter().map(|c| c.rendered.as_str()).collect();
+            match ident.as_str() {
+                "not" => Some(RustCfg {
+                    rendered: format!("not({})", rendered_children.first()?),
+                    kind: "not".to_string(),
+                    key: None,
+                    value: None,
+                    children,
+                }),
+                "all" | "any" => Some(RustCfg {
+                    rendered: format!("{}({})", ident, rendered_children.join(", ")),
+                    kind: ident,
+                    key: None,
+                    value: None,
+                    children,
+                }),
+                _ => None,
+            }
+        }
     }
 }
 
+/// Extract and normalize the `#[cfg(...)]` predicate(s) on an item's attributes.
+/// Multiple `#[cfg(...)]` attributes (or comma-separated terms within one) on the
+/// same item are implicitly AND-ed together, matching rustc's own semantics.
+fn extract_cfg(attrs: &[syn::Attribute]) -> Option<RustCfg> {
+    let mut exprs = Vec::new();
+
+    for attr in attrs {
+        if !attr.path().is_ident("cfg") {
+            continue;
+        }
+        let syn::Meta::List(list) = &attr.meta else {
+            continue;
+        };
+        let Ok(inner) = list.parse_args_with(
+            syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
+        ) else {
+            continue;
+        };
+        let parts: Vec<RustCfg> = inner.iter().filter_map(parse_cfg_meta).collect();
+        match parts.len() {
+            0 => {}
+            1 => exprs.push(parts.into_iter().next().unwrap()),
+            _ => exprs.push(combine_as_all(parts)),
+        }
+    }
+
+    match exprs.len() {
+        0 => None,
+        1 => Some(exprs.into_iter().next().unwrap()),
+        _ => Some(combine_as_all(exprs)),
+    }
+}
+
+/// Combine an enclosing item's already-resolved effective cfg (an impl block's
+/// or a trait's) with an item's own `#[cfg(...)]`, so methods/assoc consts
+/// inherit their parent's gate the same way `ItemCollector::effective_cfg`
+/// makes nested items inherit an enclosing `mod`'s gate.
+fn merge_cfg(enclosing: Option<&RustCfg>, own: Option<RustCfg>) -> Option<RustCfg> {
+    match (enclosing, own) {
+        (None, None) => None,
+        (Some(e), None) => Some(e.clone()),
+        (None, Some(o)) => Some(o),
+        (Some(e), Some(o)) => Some(combine_as_all(vec![e.clone(), o])),
+    }
+}
+
+/// Combine several predicates into a single implicit `all(...)` predicate.
+fn combine_as_all(parts: Vec<RustCfg>) -> RustCfg {
+    let rendered = format!(
+        "all({})",
+        parts.iter().map(|p| p.rendered.as_str()).collect::<Vec<_>>().join(", ")
+    );
+    RustCfg {
+        kind: "all".to_string(),
+        key: None,
+        value: None,
+        children: parts,
+        rendered,
+    }
+}
+
+/// Read a `key = "value"` pair out of a `#[deprecated(...)]`/`#[stable(...)]`/
+/// `#[unstable(...)]` attribute's nested meta list.
+fn meta_name_value_str(meta: &syn::Meta, key: &str) -> Option<String> {
+    let syn::Meta::List(list) = meta else {
+        return None;
+    };
+    let inner = list
+        .parse_args_with(syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated)
+        .ok()?;
+    inner.iter().find_map(|m| {
+        if let syn::Meta::NameValue(nv) = m {
+            if nv.path.is_ident(key) {
+                if let syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(s),
+                    ..
+                }) = &nv.value
+                {
+                    return Some(s.value());
+                }
+            }
+        }
+        None
+    })
+}
+
+/// Extract `#[deprecated(...)]`/`#[stable(...)]`/`#[unstable(...)]` metadata, the
+/// same attributes rustdoc's `clean` step surfaces as `Deprecation`/`Stability`.
+fn extract_stability(attrs: &[syn::Attribute]) -> Option<RustStability> {
+    let deprecated_attr = attrs.iter().find(|a| a.path().is_ident("deprecated"));
+    let stability_attr = attrs
+        .iter()
+        .find(|a| a.path().is_ident("stable") || a.path().is_ident("unstable"));
+
+    if deprecated_attr.is_none() && stability_attr.is_none() {
+        return None;
+    }
+
+    let is_deprecated = deprecated_attr.is_some();
+    let (deprecated_since, deprecated_note) = match deprecated_attr {
+        Some(attr) => (
+            meta_name_value_str(&attr.meta, "since"),
+            meta_name_value_str(&attr.meta, "note"),
+        ),
+        None => (None, None),
+    };
+
+    let (stability, stable_since, feature) = match stability_attr {
+        Some(attr) => {
+            let kind = if attr.path().is_ident("stable") {
+                "stable"
+            } else {
+                "unstable"
+            };
+            (
+                Some(kind.to_string()),
+                meta_name_value_str(&attr.meta, "since"),
+                meta_name_value_str(&attr.meta, "feature"),
+            )
+        }
+        None => (None, None, None),
+    };
+
+    Some(RustStability {
+        is_deprecated,
+        deprecated_since,
+        deprecated_note,
+        stability,
+        stable_since,
+        feature,
+    })
+}
+
 fn extract_doc_comment(attrs: &[syn::Attribute]) -> Option<String> {
     let docs: Vec<String> = attrs
         .iter()
@@ -926,12 +2021,15 @@ fn extract_doc_comment(attrs: &[syn::Attribute]) -> Option<String> {
     }
 }
 
-fn parse_function(node: &ItemFn, module_path: &str) -> RustFunction {
+fn parse_function(node: &ItemFn, module_path: &str, cfg_gate: Option<RustCfg>) -> RustFunction {
     let name = node.sig.ident.to_string();
     let params = parse_fn_params(&node.sig.inputs);
     let return_type = parse_return_type(&node.sig.output);
     let is_async = node.sig.asyncness.is_some();
     let doc = extract_doc_comment(&node.attrs);
+    let generics = extract_generics(&node.sig.generics);
+    let stability = extract_stability(&node.attrs);
+    let (is_const, is_unsafe, abi) = extract_fn_qualifiers(&node.sig);
 
     RustFunction {
         name,
@@ -941,15 +2039,54 @@ fn parse_function(node: &ItemFn, module_path: &str) -> RustFunction {
         is_async,
         doc,
         module_path: module_path.to_string(),
+        generics,
+        cfg_gate,
+        stability,
+        is_const,
+        is_unsafe,
+        abi,
+    }
+}
+
+fn parse_method(node: &syn::ImplItemFn, enclosing_cfg: Option<&RustCfg>) -> RustMethod {
+    let name = node.sig.ident.to_string();
+    let (params, self_type) = parse_method_params(&node.sig.inputs);
+    let return_type = parse_return_type(&node.sig.output);
+    let is_static = self_type.is_empty();
+    let doc = extract_doc_comment(&node.attrs);
+    let stability = extract_stability(&node.attrs);
+    let (is_const, is_unsafe, abi) = extract_fn_qualifiers(&node.sig);
+    let generics = extract_generics(&node.sig.generics);
+    let cfg_gate = merge_cfg(enclosing_cfg, extract_cfg(&node.attrs));
+
+    RustMethod {
+        name,
+        params,
+        return_type,
+        self_type,
+        is_pub: true,
+        is_static,
+        doc,
+        has_body: true,
+        stability,
+        is_const,
+        is_unsafe,
+        abi,
+        generics,
+        cfg_gate,
     }
 }
 
-fn parse_method(node: &syn::ImplItemFn) -> RustMethod {
+fn parse_trait_method(node: &syn::TraitItemFn, enclosing_cfg: Option<&RustCfg>) -> RustMethod {
     let name = node.sig.ident.to_string();
     let (params, self_type) = parse_method_params(&node.sig.inputs);
     let return_type = parse_return_type(&node.sig.output);
     let is_static = self_type.is_empty();
     let doc = extract_doc_comment(&node.attrs);
+    let stability = extract_stability(&node.attrs);
+    let (is_const, is_unsafe, abi) = extract_fn_qualifiers(&node.sig);
+    let generics = extract_generics(&node.sig.generics);
+    let cfg_gate = merge_cfg(enclosing_cfg, extract_cfg(&node.attrs));
 
     RustMethod {
         name,
@@ -959,6 +2096,13 @@ fn parse_method(node: &syn::ImplItemFn) -> RustMethod {
         is_pub: true,
         is_static,
         doc,
+        stability,
+        has_body: node.default.is_some(),
+        is_const,
+        is_unsafe,
+        abi,
+        generics,
+        cfg_gate,
     }
 }
 
@@ -1038,7 +2182,7 @@ fn parse_return_type(output: &ReturnType) -> Option<String> {
     }
 }
 
-fn parse_struct(node: &ItemStruct, module_path: &str) -> RustStruct {
+fn parse_struct(node: &ItemStruct, module_path: &str, cfg_gate: Option<RustCfg>) -> RustStruct {
     let name = node.ident.to_string();
     let fields = match &node.fields {
         syn::Fields::Named(named) => named
@@ -1063,6 +2207,8 @@ fn parse_struct(node: &ItemStruct, module_path: &str) -> RustStruct {
         syn::Fields::Unit => Vec::new(),
     };
     let doc = extract_doc_comment(&node.attrs);
+    let generics = extract_generics(&node.generics);
+    let stability = extract_stability(&node.attrs);
 
     RustStruct {
         name,
@@ -1070,10 +2216,13 @@ fn parse_struct(node: &ItemStruct, module_path: &str) -> RustStruct {
         is_pub: true,
         doc,
         module_path: module_path.to_string(),
+        generics,
+        cfg_gate,
+        stability,
     }
 }
 
-fn parse_enum(node: &ItemEnum, module_path: &str) -> RustEnum {
+fn parse_enum(node: &ItemEnum, module_path: &str, cfg_gate: Option<RustCfg>) -> RustEnum {
     let name = node.ident.to_string();
     let variants = node
         .variants
@@ -1101,13 +2250,19 @@ fn parse_enum(node: &ItemEnum, module_path: &str) -> RustEnum {
                     .collect(),
                 syn::Fields::Unit => Vec::new(),
             };
+            let discriminant = v.discriminant.as_ref().map(|(_, expr)| expr_to_string(expr));
             RustVariant {
                 name: v.ident.to_string(),
                 fields,
+                discriminant,
             }
         })
-        .collect();
+        .collect::<Vec<RustVariant>>();
+    let is_fieldless = variants.iter().all(|v| v.fields.is_empty());
     let doc = extract_doc_comment(&node.attrs);
+    let generics = extract_generics(&node.generics);
+    let stability = extract_stability(&node.attrs);
+    let repr = extract_repr(&node.attrs);
 
     RustEnum {
         name,
@@ -1115,10 +2270,83 @@ fn parse_enum(node: &ItemEnum, module_path: &str) -> RustEnum {
         is_pub: true,
         doc,
         module_path: module_path.to_string(),
+        generics,
+        cfg_gate,
+        stability,
+        repr,
+        is_fieldless,
     }
 }
 
-fn parse_type_alias(node: &ItemType) -> RustTypeAlias {
+fn parse_trait(node: &ItemTrait, module_path: &str, cfg_gate: Option<RustCfg>) -> RustTrait {
+    let name = node.ident.to_string();
+    let doc = extract_doc_comment(&node.attrs);
+
+    let supertraits: Vec<String> = node
+        .supertraits
+        .iter()
+        .filter_map(|bound| {
+            if let TypeParamBound::Trait(trait_bound) = bound {
+                trait_bound.path.segments.last().map(|s| s.ident.to_string())
+            } else {
+                None
+            }
+        })
+        .collect();
+    let generics = extract_generics(&node.generics);
+
+    let mut methods = Vec::new();
+    let mut assoc_types = Vec::new();
+    let mut assoc_consts = Vec::new();
+
+    for item in &node.items {
+        match item {
+            TraitItem::Fn(method) => methods.push(parse_trait_method(method, cfg_gate.as_ref())),
+            TraitItem::Type(assoc_type) => {
+                let bounds: Vec<String> = assoc_type
+                    .bounds
+                    .iter()
+                    .map(bound_to_string)
+                    .collect();
+                let default_type = assoc_type
+                    .default
+                    .as_ref()
+                    .map(|(_, ty)| type_to_string(ty));
+                assoc_types.push(RustAssocType {
+                    name: assoc_type.ident.to_string(),
+                    bounds,
+                    default_type,
+                    doc: extract_doc_comment(&assoc_type.attrs),
+                });
+            }
+            TraitItem::Const(assoc_const) => assoc_consts.push(RustConstant {
+                name: assoc_const.ident.to_string(),
+                rust_type: type_to_string(&assoc_const.ty),
+                is_pub: true,
+                doc: extract_doc_comment(&assoc_const.attrs),
+                module_path: module_path.to_string(),
+                cfg_gate: merge_cfg(cfg_gate.as_ref(), extract_cfg(&assoc_const.attrs)),
+                stability: extract_stability(&assoc_const.attrs),
+            }),
+            _ => {}
+        }
+    }
+
+    RustTrait {
+        name,
+        supertraits,
+        methods,
+        assoc_types,
+        assoc_consts,
+        is_pub: true,
+        doc,
+        module_path: module_path.to_string(),
+        cfg_gate,
+        generics,
+    }
+}
+
+fn parse_type_alias(node: &ItemType, module_path: &str, cfg_gate: Option<RustCfg>) -> RustTypeAlias {
     let name = node.ident.to_string();
     let target_type = type_to_string(&node.ty);
     let doc = extract_doc_comment(&node.attrs);
@@ -1134,12 +2362,164 @@ fn parse_type_alias(node: &ItemType) -> RustTypeAlias {
         })
         .collect();
 
+    let generics_info = extract_generics(&node.generics);
+    let stability = extract_stability(&node.attrs);
+
     RustTypeAlias {
         name,
         target_type,
         generics,
         is_pub: true,
         doc,
+        module_path: module_path.to_string(),
+        generics_info,
+        cfg_gate,
+        stability,
+    }
+}
+
+/// Fall back when `syn::parse_file` rejects a whole file (newer syntax it doesn't
+/// support yet, or a file that's mid-edit): split it into bracket/semicolon-balanced
+/// top-level chunks and parse each independently. This salvages every other item in
+/// the file instead of losing the whole file to one bad item.
+///
+/// `split_into_item_chunks` is a heuristic, not a real parser — it only tracks
+/// string literals and `{}`/`()`/`[]` nesting depth, so a bracket or `"` inside a
+/// comment or char literal can throw off a chunk boundary. Good enough for
+/// salvage; not a replacement for `syn::parse_file` on well-formed input.
+///
+/// Chunks that still fail to parse are recorded on `errors` (with a byte offset
+/// into `content`) rather than silently dropped, so callers can see exactly what
+/// was lost instead of just a smaller-than-expected item count.
+fn salvage_items(content: &str, file: &str, collector: &mut ItemCollector, errors: &mut Vec<RustParseError>) {
+    let mut offset = 0usize;
+    for chunk in split_into_item_chunks(content) {
+        match syn::parse_str::<syn::Item>(&chunk) {
+            Ok(item) => syn::visit::visit_item(collector, &item),
+            Err(e) => errors.push(RustParseError {
+                file: file.to_string(),
+                byte_offset: offset,
+                message: e.to_string(),
+            }),
+        }
+        offset += chunk.len();
+    }
+}
+
+fn split_into_item_chunks(content: &str) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut escape = false;
+
+    for c in content.chars() {
+        current.push(c);
+        if in_string {
+            if escape {
+                escape = false;
+            } else if c == '\\' {
+                escape = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' | '(' | '[' => depth += 1,
+            ')' | ']' => depth = (depth - 1).max(0),
+            '}' => {
+                depth = (depth - 1).max(0);
+                if depth == 0 && !current.trim().is_empty() {
+                    chunks.push(std::mem::take(&mut current));
+                }
+            }
+            ';' if depth == 0 && !current.trim().is_empty() => {
+                chunks.push(std::mem::take(&mut current));
+            }
+            _ => {}
+        }
+    }
+    if !current.trim().is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod salvage_tests {
+    use super::*;
+
+    #[test]
+    fn array_const_semicolon_is_not_an_item_boundary() {
+        let content = "pub const ARR: [u8; 32] = [0u8; 32];\npub fn foo() -> i32 { 1 }\n";
+        let chunks = split_into_item_chunks(content);
+        assert_eq!(chunks.len(), 2);
+        assert!(syn::parse_str::<syn::Item>(chunks[0].trim()).is_ok());
+        assert!(syn::parse_str::<syn::Item>(chunks[1].trim()).is_ok());
+    }
+
+    #[test]
+    fn salvage_recovers_valid_items_around_an_unparseable_one() {
+        let mut collector = ItemCollector::new();
+        let mut errors = Vec::new();
+        let content = "pub const ARR: [u8; 4] = [0; 4];\nfn 1broken() { let x = ; }\npub fn foo() -> i32 { 1 }\n";
+        salvage_items(content, "src/broken.rs", &mut collector, &mut errors);
+        assert_eq!(collector.constants.len(), 1);
+        assert_eq!(collector.functions.len(), 1);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].file, "src/broken.rs");
+        // The errored chunk starts right where the prior chunk's trailing `;`
+        // left off, so its offset is the newline just before `fn 1broken`.
+        let broken_chunk_offset = content.find("\nfn 1broken").unwrap();
+        assert_eq!(errors[0].byte_offset, broken_chunk_offset);
+    }
+}
+
+#[cfg(test)]
+mod symbol_index_tests {
+    use super::*;
+
+    fn test_struct(name: &str, module_path: &str) -> RustStruct {
+        RustStruct {
+            name: name.to_string(),
+            fields: Vec::new(),
+            is_pub: true,
+            doc: None,
+            module_path: module_path.to_string(),
+            generics: None,
+            cfg_gate: None,
+            stability: None,
+        }
+    }
+
+    #[test]
+    fn lookup_is_a_case_insensitive_prefix_query() {
+        let structs = vec![test_struct("Config", "crate::config"), test_struct("Connection", "crate::net")];
+        let index = build_fst_index(&structs, &[], &[], &[], &[]);
+
+        let mut names: Vec<String> = index.lookup("conn").into_iter().map(|m| m.name).collect();
+        names.sort();
+        assert_eq!(names, vec!["Connection".to_string()]);
+
+        // Case folding applies to both the stored name and the query.
+        let mut names: Vec<String> = index.lookup("CON").into_iter().map(|m| m.name).collect();
+        names.sort();
+        assert_eq!(names, vec!["Config".to_string(), "Connection".to_string()]);
+    }
+
+    #[test]
+    fn fuzzy_matches_within_edit_distance() {
+        let structs = vec![test_struct("Config", "crate::config")];
+        let index = build_fst_index(&structs, &[], &[], &[], &[]);
+
+        let matches = index.fuzzy_matches("Confg", 1).unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].name, "Config");
+        assert_eq!(matches[0].module_paths, vec!["crate::config".to_string()]);
+
+        assert!(index.fuzzy_matches("Zzzzzz", 1).unwrap().is_empty());
     }
 }
 
@@ -1149,13 +2529,14 @@ fn parse_file_internal(path: &str, module_path: &str) -> PyResult<RustCrate> {
         PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Failed to read file: {}", e))
     })?;
 
-    let syntax = syn::parse_file(&content).map_err(|e| {
-        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to parse Rust: {}", e))
-    })?;
-
     let mut collector = ItemCollector::new();
     collector.current_module = module_path.to_string();
-    collector.visit_file(&syntax);
+
+    let mut parse_errors = Vec::new();
+    match syn::parse_file(&content) {
+        Ok(syntax) => collector.visit_file(&syntax),
+        Err(_) => salvage_items(&content, path, &mut collector, &mut parse_errors),
+    }
 
     let name = Path::new(path)
         .file_stem()
@@ -1168,6 +2549,7 @@ fn parse_file_internal(path: &str, module_path: &str) -> PyResult<RustCrate> {
         structs: collector.structs,
         enums: collector.enums,
         impls: collector.impls,
+        traits: collector.traits,
         type_aliases: collector.type_aliases,
         reexports: collector.reexports,
         constants: collector.constants,
@@ -1176,6 +2558,7 @@ fn parse_file_internal(path: &str, module_path: &str) -> PyResult<RustCrate> {
         macros: collector.macros,
         available_features: Vec::new(),  // Single file has no Cargo.toml
         default_features: Vec::new(),
+        parse_errors,
     })
 }
 
@@ -1219,9 +2602,11 @@ fn parse_cargo_features(content: &str) -> (Vec<String>, Vec<String>) {
     (available_features, default_features)
 }
 
-/// Parse an entire Rust crate directory
+/// Parse an entire Rust crate directory. Files are walked up front, then parsed in
+/// parallel with rayon (releasing the GIL for the duration via `allow_threads`,
+/// since per-file parsing is pure Rust/`syn` work with no Python callbacks).
 #[pyfunction]
-fn parse_crate(path: &str) -> PyResult<RustCrate> {
+fn parse_crate(py: Python<'_>, path: &str) -> PyResult<RustCrate> {
     let crate_path = Path::new(path);
 
     // Try to find crate name and features from Cargo.toml
@@ -1263,69 +2648,80 @@ fn parse_crate(path: &str) -> PyResult<RustCrate> {
     let mut all_structs = Vec::new();
     let mut all_enums = Vec::new();
     let mut all_impls = Vec::new();
+    let mut all_traits = Vec::new();
     let mut all_type_aliases = Vec::new();
     let mut all_reexports = Vec::new();
     let mut all_constants = Vec::new();
     let mut all_statics = Vec::new();
     let mut all_enum_variant_aliases = Vec::new();
     let mut all_macros = Vec::new();
+    let mut all_parse_errors = Vec::new();
 
-    for entry in WalkDir::new(search_path)
+    // Compute (file_path, module_path) up front so the parse step below can run
+    // each file independently in parallel.
+    let files: Vec<(std::path::PathBuf, String)> = WalkDir::new(search_path)
         .into_iter()
         .filter_map(|e| e.ok())
         .filter(|e| e.path().extension().map(|ext| ext == "rs").unwrap_or(false))
-    {
-        let file_path = entry.path();
-
-        // Compute module path from file path relative to search_path
-        // e.g., src/jws/mod.rs -> "jws", src/jws/alg/hmac.rs -> "jws::alg::hmac"
-        let module_path = file_path
-            .strip_prefix(search_path)
-            .ok()
-            .and_then(|rel| {
-                let mut parts: Vec<&str> = rel
-                    .components()
-                    .filter_map(|c| c.as_os_str().to_str())
-                    .collect();
-                // Remove the file name
-                if let Some(last) = parts.last() {
-                    if last.ends_with(".rs") {
-                        parts.pop();
+        .map(|entry| {
+            let file_path = entry.path().to_path_buf();
+
+            // Compute module path from file path relative to search_path
+            // e.g., src/jws/mod.rs -> "jws", src/jws/alg/hmac.rs -> "jws::alg::hmac"
+            let module_path = file_path
+                .strip_prefix(search_path)
+                .ok()
+                .map(|rel| {
+                    let mut parts: Vec<&str> = rel
+                        .components()
+                        .filter_map(|c| c.as_os_str().to_str())
+                        .collect();
+                    // Remove the file name
+                    if let Some(last) = parts.last() {
+                        if last.ends_with(".rs") {
+                            parts.pop();
+                        }
                     }
-                }
-                // If the file was mod.rs or lib.rs, use the parent path
-                if let Some(stem) = file_path.file_stem().and_then(|s| s.to_str()) {
-                    if stem != "mod" && stem != "lib" {
-                        // For regular files like alg/hmac.rs, add the stem
-                        parts.push(stem);
+                    // If the file was mod.rs or lib.rs, use the parent path
+                    if let Some(stem) = file_path.file_stem().and_then(|s| s.to_str()) {
+                        if stem != "mod" && stem != "lib" {
+                            // For regular files like alg/hmac.rs, add the stem
+                            parts.push(stem);
+                        }
                     }
-                }
-                if parts.is_empty() {
-                    Some(String::new())
-                } else {
-                    Some(parts.join("::"))
-                }
+                    parts.join("::")
+                })
+                .unwrap_or_default();
+
+            (file_path, module_path)
+        })
+        .collect();
+
+    // Parse every file in parallel; per-file parsing is pure Rust/`syn` work, so
+    // release the GIL for the duration instead of holding it across the fan-out.
+    let parsed_files: Vec<RustCrate> = py.allow_threads(|| {
+        use rayon::prelude::*;
+        files
+            .par_iter()
+            .filter_map(|(file_path, module_path)| {
+                parse_file_internal(file_path.to_str().unwrap_or_default(), module_path).ok()
             })
-            .unwrap_or_default();
-
-        match parse_file_internal(file_path.to_str().unwrap_or_default(), &module_path) {
-            Ok(parsed) => {
-                all_functions.extend(parsed.functions);
-                all_structs.extend(parsed.structs);
-                all_enums.extend(parsed.enums);
-                all_impls.extend(parsed.impls);
-                all_type_aliases.extend(parsed.type_aliases);
-                all_reexports.extend(parsed.reexports);
-                all_constants.extend(parsed.constants);
-                all_statics.extend(parsed.statics);
-                all_enum_variant_aliases.extend(parsed.enum_variant_aliases);
-                all_macros.extend(parsed.macros);
-            }
-            Err(_) => {
-                // Skip files that fail to parse
-                continue;
-            }
-        }
+            .collect()
+    });
+
+    for parsed in parsed_files {
+        all_functions.extend(parsed.functions);
+        all_structs.extend(parsed.structs);
+        all_enums.extend(parsed.enums);
+        all_impls.extend(parsed.impls);
+        all_traits.extend(parsed.traits);
+        all_type_aliases.extend(parsed.type_aliases);
+        all_reexports.extend(parsed.reexports);
+        all_constants.extend(parsed.constants);
+        all_statics.extend(parsed.statics);
+        all_enum_variant_aliases.extend(parsed.enum_variant_aliases);
+        all_macros.extend(parsed.macros);
+        all_parse_errors.extend(parsed.parse_errors);
     }
 
     Ok(RustCrate {
@@ -1334,6 +2730,7 @@ fn parse_crate(path: &str) -> PyResult<RustCrate> {
         structs: all_structs,
         enums: all_enums,
         impls: all_impls,
+        traits: all_traits,
         type_aliases: all_type_aliases,
         reexports: all_reexports,
         constants: all_constants,
@@ -1342,6 +2739,7 @@ fn parse_crate(path: &str) -> PyResult<RustCrate> {
         macros: all_macros,
         available_features,
         default_features,
+        parse_errors: all_parse_errors,
     })
 }
 
@@ -1401,6 +2799,8 @@ fn _parser(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(format_rust_code, m)?)?;
     m.add_function(wrap_pyfunction!(validate_and_format_rust, m)?)?;
     m.add_class::<RustTypeInfo>()?;
+    m.add_class::<RustGenerics>()?;
+    m.add_class::<RustStability>()?;
     m.add_class::<RustParam>()?;
     m.add_class::<RustFunction>()?;
     m.add_class::<RustField>()?;
@@ -1410,7 +2810,14 @@ fn _parser(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<RustEnum>()?;
     m.add_class::<RustMethod>()?;
     m.add_class::<RustImpl>()?;
+    m.add_class::<RustTrait>()?;
+    m.add_class::<RustAssocType>()?;
+    m.add_class::<RustCfg>()?;
+    m.add_class::<RustSymbolIndex>()?;
+    m.add_class::<RustSymbolMatch>()?;
     m.add_class::<RustCrate>()?;
+    m.add_class::<RustResolvedReference>()?;
+    m.add_class::<RustParseError>()?;
     m.add_class::<RustReexport>()?;
     m.add_class::<RustConstant>()?;
     m.add_class::<RustStatic>()?;